@@ -0,0 +1,288 @@
+//! Async driver variant built on `embedded-hal-async`.
+//!
+//! This mirrors the blocking driver in [`crate`] but uses the async
+//! `SpiDevice`/`I2c` traits so the driver can participate in Embassy/RTIC
+//! executors instead of blocking the CPU on the bus. It is gated behind the
+//! `async` cargo feature; the default blocking path is unaffected.
+
+use core::convert::TryFrom;
+use core::fmt::Debug;
+
+use embedded_hal_async::i2c::I2c;
+use embedded_hal_async::spi::{Operation, SpiDevice};
+
+use crate::commbus::Address;
+use crate::register::*;
+use crate::Error;
+
+pub use accelerometer::vector::{F32x3, I16x3};
+
+/// Async counterpart of [`crate::commbus::CommBus`].
+pub trait CommBus {
+    /// Interface associated error type
+    type CommError;
+
+    async fn read_bytes(&mut self, register: u8, bytes: &mut [u8])
+        -> Result<(), Self::CommError>;
+
+    /// Write a byte to the given register.
+    async fn write_register(&mut self, register: u8, value: u8) -> Result<(), Self::CommError>;
+
+    /// Read a byte from the given register.
+    async fn read_register(&mut self, register: u8) -> Result<u8, Self::CommError>;
+}
+
+pub struct SPIBus<SPI> {
+    spi: SPI,
+}
+
+impl<SPI> SPIBus<SPI> {
+    pub fn new(spi: SPI) -> Self {
+        Self { spi }
+    }
+}
+
+impl<SPI, E> CommBus for SPIBus<SPI>
+where
+    SPI: SpiDevice<u8, Error = E>,
+{
+    type CommError = Error<E, core::convert::Infallible>;
+
+    async fn read_bytes(
+        &mut self,
+        register: u8,
+        bytes: &mut [u8],
+    ) -> Result<(), Self::CommError> {
+        // One framed transaction keeps CS asserted across the sub-address
+        // write and the burst read; two separate calls would de-assert CS in
+        // between and the read would run with no address set.
+        self.spi
+            .transaction(&mut [Operation::Write(&[register]), Operation::TransferInPlace(bytes)])
+            .await
+            .map_err(Error::CommErr)
+    }
+
+    async fn read_register(&mut self, register: u8) -> Result<u8, Self::CommError> {
+        let mut bytes = [register, 0];
+        self.spi
+            .transfer_in_place(&mut bytes)
+            .await
+            .map_err(Error::CommErr)?;
+        Ok(bytes[1])
+    }
+
+    async fn write_register(&mut self, register: u8, value: u8) -> Result<(), Self::CommError> {
+        self.spi
+            .write(&[register, value])
+            .await
+            .map_err(Error::CommErr)
+    }
+}
+
+pub struct I2CBus<I2C> {
+    i2c: I2C,
+    address: Address,
+}
+
+impl<I2C> I2CBus<I2C> {
+    pub fn new(i2c: I2C, address: Address) -> Self {
+        Self { i2c, address }
+    }
+}
+
+impl<I2C, E> CommBus for I2CBus<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    type CommError = Error<E, core::convert::Infallible>;
+
+    async fn read_bytes(
+        &mut self,
+        register: u8,
+        bytes: &mut [u8],
+    ) -> Result<(), Self::CommError> {
+        let sub = register | 0x80;
+        self.i2c
+            .write_read(self.address as u8, &[sub], bytes)
+            .await
+            .map_err(Error::CommErr)
+    }
+
+    async fn read_register(&mut self, register: u8) -> Result<u8, Self::CommError> {
+        let mut byte = [0u8];
+        self.i2c
+            .write_read(self.address as u8, &[register & 0x7F], &mut byte)
+            .await
+            .map_err(Error::CommErr)?;
+        Ok(byte[0])
+    }
+
+    async fn write_register(&mut self, register: u8, value: u8) -> Result<(), Self::CommError> {
+        self.i2c
+            .write(self.address as u8, &[register & 0x7F, value])
+            .await
+            .map_err(Error::CommErr)
+    }
+}
+
+pub struct LIS3DSH<CB> {
+    pub(crate) commbus: CB,
+}
+
+impl<CB, E, PinError> LIS3DSH<CB>
+where
+    CB: CommBus<CommError = crate::Error<E, PinError>>,
+    PinError: Debug,
+    E: Debug,
+{
+    pub async fn new_with_interface<DELAY>(
+        commbus: CB,
+        delay: &mut DELAY,
+    ) -> Result<LIS3DSH<CB>, Error<E, PinError>>
+    where
+        DELAY: embedded_hal_async::delay::DelayNs,
+    {
+        let mut x = LIS3DSH { commbus };
+        x.init(delay).await?;
+
+        Ok(x)
+    }
+
+    async fn init<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), Error<E, PinError>>
+    where
+        DELAY: embedded_hal_async::delay::DelayNs,
+    {
+        self.commbus
+            .write_register(Register::CTRL_REG3.write(), STRESET)
+            .await?;
+        delay.delay_ms(5).await;
+
+        self.commbus
+            .write_register(Register::CTRL_REG3.write(), 0)
+            .await?;
+        delay.delay_ms(5).await;
+
+        self.commbus
+            .write_register(Register::CTRL_REG5.write(), 0)
+            .await?;
+
+        self.commbus
+            .write_register(Register::CTRL_REG4.write(), BDU)
+            .await?;
+
+        if self.get_device_id().await? != DEVICE_ID {
+            return Err(Error::WrongAddress);
+        }
+
+        self.set_datarate(DataRate::Hz_100).await?;
+        self.set_range(Range::G8).await?;
+
+        self.enable_axis((true, true, true)).await?;
+
+        Ok(())
+    }
+
+    /// `WHO_AM_I` register.
+    pub async fn get_device_id(&mut self) -> Result<u8, Error<E, PinError>> {
+        self.commbus.read_register(Register::WHOAMI.read()).await
+    }
+
+    async fn enable_axis(
+        &mut self,
+        (x, y, z): (bool, bool, bool),
+    ) -> Result<(), Error<E, PinError>> {
+        let mut v = self.commbus.read_register(Register::CTRL_REG4.read()).await?;
+
+        v &= !(X_EN | Y_EN | Z_EN);
+        v |= if x { X_EN } else { 0 };
+        v |= if y { Y_EN } else { 0 };
+        v |= if z { Z_EN } else { 0 };
+
+        self.commbus
+            .write_register(Register::CTRL_REG4.write(), v)
+            .await
+    }
+
+    pub async fn set_datarate(&mut self, datarate: DataRate) -> Result<(), Error<E, PinError>> {
+        let mut v = self.commbus.read_register(Register::CTRL_REG4.read()).await?;
+        v &= !ODR_MASK;
+        v |= datarate.bits() << ODR_OFFSET;
+        self.commbus
+            .write_register(Register::CTRL_REG4.write(), v)
+            .await
+    }
+
+    async fn get_datarate(&mut self) -> Result<DataRate, Error<E, PinError>> {
+        let ctrl4 = self.commbus.read_register(Register::CTRL_REG4.read()).await?;
+        let odr = (ctrl4 & ODR_MASK) >> ODR_OFFSET;
+
+        DataRate::try_from(odr).map_err(|_| Error::InvalidDataRate)
+    }
+
+    async fn set_range(&mut self, range: Range) -> Result<(), Error<E, PinError>> {
+        let mut ctrl5 = self.commbus.read_register(Register::CTRL_REG5.read()).await?;
+
+        ctrl5 &= !FS_MASK;
+        ctrl5 |= (range.bits() << FS_OFFSET) & FS_MASK;
+        ctrl5 &= !0x1;
+
+        self.commbus
+            .write_register(Register::CTRL_REG5.write(), ctrl5)
+            .await
+    }
+
+    pub async fn get_range(&mut self) -> Result<Range, Error<E, PinError>> {
+        let ctrl5 = self.commbus.read_register(Register::CTRL_REG5.read()).await?;
+
+        let fs = (ctrl5 & FS_MASK) >> FS_OFFSET;
+
+        Range::try_from(fs).map_err(|_| Error::InvalidRange)
+    }
+
+    async fn get_status(&mut self) -> Result<DataStatus, Error<E, PinError>> {
+        let stat = self.commbus.read_register(Register::STATUS.read()).await?;
+
+        Ok(DataStatus::from(stat))
+    }
+
+    pub async fn has_data(&mut self) -> Result<bool, Error<E, PinError>> {
+        Ok(self.get_status().await?.zyxda())
+    }
+
+    /// Raw acceleration vector.
+    pub async fn accel_raw(&mut self) -> Result<I16x3, Error<E, PinError>> {
+        let mut accel_bytes = [0u8; 6];
+        self.commbus
+            .read_bytes(Register::OUT_X_L.read(), &mut accel_bytes)
+            .await?;
+
+        let x = (((accel_bytes[1] as u16) << 8) | (accel_bytes[0] as u16)) as i16;
+        let y = (((accel_bytes[3] as u16) << 8) | (accel_bytes[2] as u16)) as i16;
+        let z = (((accel_bytes[5] as u16) << 8) | (accel_bytes[4] as u16)) as i16;
+
+        Ok(I16x3::new(x, y, z))
+    }
+
+    /// Acceleration normalized to g.
+    pub async fn accel_norm(&mut self) -> Result<F32x3, Error<E, PinError>> {
+        let scale = match self.get_range().await? {
+            Range::G2 => 0.06,
+            Range::G4 => 0.12,
+            Range::G6 => 0.18,
+            Range::G8 => 0.24,
+            Range::G16 => 0.73,
+        } / 1000.0;
+
+        let acc_raw = self.accel_raw().await?;
+        let x = (acc_raw.x as f32) * scale;
+        let y = (acc_raw.y as f32) * scale;
+        let z = (acc_raw.z as f32) * scale;
+
+        Ok(F32x3::new(x, y, z))
+    }
+
+    /// Get the sample rate of the accelerometer data.
+    pub async fn sample_rate(&mut self) -> Result<f32, Error<E, PinError>> {
+        Ok(self.get_datarate().await?.sample_rate())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::commbus::CommBus;
+use crate::Error;
+
+use embedded_hal::blocking::i2c::{Write, WriteRead};
+
+/// I2C slave address, selected by the SDO/SA0 pin.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Address {
+    /// SDO/SA0 tied low (`0x1E`, default).
+    Default = 0x1E,
+
+    /// SDO/SA0 tied high (`0x1F`).
+    Alternate = 0x1F,
+}
+
+impl Address {
+    fn addr(self) -> u8 {
+        self as u8
+    }
+}
+
+pub struct I2CBus<I2C> {
+    i2c: I2C,
+    address: Address,
+}
+
+impl<I2C> I2CBus<I2C> {
+    pub fn new(i2c: I2C, address: Address) -> Self {
+        Self { i2c, address }
+    }
+}
+
+impl<I2C, E> CommBus for I2CBus<I2C>
+where
+    I2C: Write<Error = E> + WriteRead<Error = E>,
+{
+    type CommError = Error<E, core::convert::Infallible>;
+
+    fn read_bytes(&mut self, register: u8, bytes: &mut [u8]) -> Result<(), Self::CommError> {
+        // Set the MSB of the sub-address so the device auto-increments across
+        // the burst instead of returning the same register repeatedly.
+        let sub = register | 0x80;
+        self.i2c
+            .write_read(self.address.addr(), &[sub], bytes)
+            .map_err(Error::CommErr)
+    }
+
+    fn read_register(&mut self, register: u8) -> Result<u8, Self::CommError> {
+        let mut byte = [0u8];
+        self.i2c
+            .write_read(self.address.addr(), &[register & 0x7F], &mut byte)
+            .map_err(Error::CommErr)?;
+        Ok(byte[0])
+    }
+
+    fn write_register(&mut self, register: u8, value: u8) -> Result<(), Self::CommError> {
+        self.i2c
+            .write(self.address.addr(), &[register & 0x7F, value])
+            .map_err(Error::CommErr)
+    }
+}
@@ -1,8 +1,8 @@
 pub mod spi;
 pub use self::spi::SPIBus;
 
-//pub mod i2c;
-//pub use self::spi::SPIBus;
+pub mod i2c;
+pub use self::i2c::{Address, I2CBus};
 
 /// A method of communicating with the device
 pub trait CommBus {
@@ -0,0 +1,161 @@
+//! Interrupt routing and inertial-event configuration.
+//!
+//! The LIS3DSH can drive two hardware interrupt pins (INT1, INT2) from a
+//! number of internal sources, letting the host sleep until something
+//! happens instead of busy-polling `STATUS`.
+
+use core::fmt::Debug;
+
+use crate::commbus::CommBus;
+use crate::register::*;
+use crate::{Error, LIS3DSH};
+
+/// Marker for the INT1 pin.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub struct Interrupt1;
+
+/// Marker for the INT2 pin.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub struct Interrupt2;
+
+impl From<Interrupt1> for InterruptPin {
+    fn from(_: Interrupt1) -> Self {
+        InterruptPin::Int1
+    }
+}
+
+impl From<Interrupt2> for InterruptPin {
+    fn from(_: Interrupt2) -> Self {
+        InterruptPin::Int2
+    }
+}
+
+/// One of the device's two interrupt pins.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum InterruptPin {
+    Int1,
+    Int2,
+}
+
+/// Signal polarity on the interrupt pin.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Polarity {
+    /// Pin is driven high while the interrupt is active (default).
+    ActiveHigh,
+    /// Pin is driven low while the interrupt is active.
+    ActiveLow,
+}
+
+/// Whether the interrupt request is latched until read or only pulsed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Latch {
+    /// Request is held until the source is read (IEL=0, power-on default).
+    Latched,
+    /// Request is a short pulse (IEL=1).
+    Pulsed,
+}
+
+/// Configuration applied to an interrupt pin.
+#[derive(Copy, Clone, Debug)]
+pub struct InterruptConfig {
+    /// Drive polarity of the pin.
+    pub polarity: Polarity,
+    /// Latched or pulsed request.
+    pub latch: Latch,
+    /// Route the data-ready signal to INT1 (DR_EN); ignored for INT2.
+    pub data_ready: bool,
+    /// Route the FIFO watermark signal to INT1 (P1_WTM).
+    pub fifo_watermark: bool,
+    /// Enable the pin's master output stage (INT1_EN / INT2_EN).
+    pub enable: bool,
+}
+
+impl Default for InterruptConfig {
+    fn default() -> Self {
+        Self {
+            polarity: Polarity::ActiveHigh,
+            // Reset state of IEL is 0 = latched.
+            latch: Latch::Latched,
+            data_ready: false,
+            fifo_watermark: false,
+            enable: false,
+        }
+    }
+}
+
+impl<CB, E, PinError> LIS3DSH<CB>
+where
+    CB: CommBus<CommError = crate::Error<E, PinError>>,
+    PinError: Debug,
+    E: Debug,
+{
+    /// Configure an interrupt pin's polarity, latching and routed sources.
+    pub fn configure_interrupt_pin(
+        &mut self,
+        pin: InterruptPin,
+        cfg: InterruptConfig,
+    ) -> Result<(), Error<E, PinError>> {
+        let mut ctrl3 = self.commbus.read_register(Register::CTRL_REG3.read())?;
+
+        match cfg.polarity {
+            Polarity::ActiveHigh => ctrl3 |= IEA,
+            Polarity::ActiveLow => ctrl3 &= !IEA,
+        }
+        // IEL=0 is latched, IEL=1 is pulsed.
+        match cfg.latch {
+            Latch::Latched => ctrl3 &= !IEL,
+            Latch::Pulsed => ctrl3 |= IEL,
+        }
+
+        // Data-ready routing (DR_EN) targets INT1 only.
+        if pin == InterruptPin::Int1 {
+            ctrl3 &= !DR_EN;
+            if cfg.data_ready {
+                ctrl3 |= DR_EN;
+            }
+        }
+
+        let en = match pin {
+            InterruptPin::Int1 => INT1_EN,
+            InterruptPin::Int2 => INT2_EN,
+        };
+        ctrl3 &= !en;
+        if cfg.enable {
+            ctrl3 |= en;
+        }
+
+        self.commbus
+            .write_register(Register::CTRL_REG3.write(), ctrl3)?;
+
+        // The FIFO watermark can only be routed to INT1 (P1_WTM).
+        if pin == InterruptPin::Int1 {
+            let mut ctrl6 = self.commbus.read_register(Register::CTRL_REG6.read())?;
+            ctrl6 &= !P1_WTM;
+            if cfg.fifo_watermark {
+                ctrl6 |= P1_WTM;
+            }
+            self.commbus
+                .write_register(Register::CTRL_REG6.write(), ctrl6)?;
+        }
+
+        Ok(())
+    }
+
+    /// Route the data-ready signal to INT1 as an active-high pulse.
+    pub fn enable_data_ready_int1(&mut self) -> Result<(), Error<E, PinError>> {
+        self.configure_interrupt_pin(
+            InterruptPin::Int1,
+            InterruptConfig {
+                data_ready: true,
+                latch: Latch::Pulsed,
+                enable: true,
+                ..InterruptConfig::default()
+            },
+        )
+    }
+
+    /// Read the current interrupt/event source flags from `STAT`.
+    pub fn get_int_source(&mut self) -> Result<u8, Error<E, PinError>> {
+        self.commbus.read_register(Register::STAT.read())
+    }
+}
@@ -5,6 +5,13 @@ pub mod commbus;
 pub mod register;
 use register::*;
 
+pub mod interrupts;
+
+pub mod state_machine;
+
+#[cfg(feature = "async")]
+pub mod asynch;
+
 use core::convert::TryFrom;
 use core::fmt::Debug;
 
@@ -172,6 +179,182 @@ where
     pub fn has_data(&mut self) -> Result<bool, Error<E, PinError>> {
         Ok(self.get_status()?.zyxda())
     }
+
+    /// Select the FIFO operating mode; `FIFO_EN` is set for every mode except
+    /// `Bypass`, which clears it.
+    pub fn set_fifo_mode(&mut self, mode: FifoMode) -> Result<(), Error<E, PinError>> {
+        let mut ctrl6 = self.commbus.read_register(Register::CTRL_REG6.read())?;
+        match mode {
+            FifoMode::Bypass => ctrl6 &= !FIFO_EN,
+            _ => ctrl6 |= FIFO_EN,
+        }
+        self.commbus
+            .write_register(Register::CTRL_REG6.write(), ctrl6)?;
+
+        let mut fctrl = self.commbus.read_register(Register::FIFO_CTRL.read())?;
+        fctrl &= !FIFO_MODE_MASK;
+        fctrl |= (mode.bits() << FIFO_MODE_OFFSET) & FIFO_MODE_MASK;
+        self.commbus
+            .write_register(Register::FIFO_CTRL.write(), fctrl)
+    }
+
+    /// Set the FIFO watermark threshold (0..=31 samples).
+    pub fn set_fifo_watermark(&mut self, threshold: u8) -> Result<(), Error<E, PinError>> {
+        let mut fctrl = self.commbus.read_register(Register::FIFO_CTRL.read())?;
+        fctrl &= !FIFO_WTM_MASK;
+        fctrl |= threshold & FIFO_WTM_MASK;
+        self.commbus
+            .write_register(Register::FIFO_CTRL.write(), fctrl)
+    }
+
+    /// Read the current FIFO status from `FIFO_SRC`.
+    pub fn fifo_status(&mut self) -> Result<FifoStatus, Error<E, PinError>> {
+        let src = self.commbus.read_register(Register::FIFO_SRC.read())?;
+        Ok(FifoStatus::from(src))
+    }
+
+    /// Read the on-chip temperature sensor (`OUT_T`).
+    ///
+    /// `OUT_T` is an 8-bit 2's-complement value with 1 LSB/°C, referenced so
+    /// that a reading of 0 corresponds to roughly 25 °C.
+    pub fn read_temperature(&mut self) -> Result<i16, Error<E, PinError>> {
+        let raw = self.commbus.read_register(Register::OUT_T.read())? as i8;
+        Ok(raw as i16 + 25)
+    }
+
+    /// [`read_temperature`](Self::read_temperature) as `f32` degrees Celsius.
+    pub fn read_temperature_f32(&mut self) -> Result<f32, Error<E, PinError>> {
+        Ok(self.read_temperature()? as f32)
+    }
+
+    /// Write the per-axis offset-correction register (`OFF_X/Y/Z`).
+    pub fn set_offset(&mut self, axis: Axis, offset: i8) -> Result<(), Error<E, PinError>> {
+        let reg = match axis {
+            Axis::X => Register::OFF_X,
+            Axis::Y => Register::OFF_Y,
+            Axis::Z => Register::OFF_Z,
+        };
+        self.commbus.write_register(reg.write(), offset as u8)
+    }
+
+    /// Read the per-axis offset-correction register (`OFF_X/Y/Z`).
+    pub fn get_offset(&mut self, axis: Axis) -> Result<i8, Error<E, PinError>> {
+        let reg = match axis {
+            Axis::X => Register::OFF_X,
+            Axis::Y => Register::OFF_Y,
+            Axis::Z => Register::OFF_Z,
+        };
+        Ok(self.commbus.read_register(reg.read())? as i8)
+    }
+
+    /// Write the per-axis constant-shift register (`CS_X/Y/Z`) used by the
+    /// click/self-test detection logic.
+    pub fn set_constant_shift(&mut self, axis: Axis, value: i8) -> Result<(), Error<E, PinError>> {
+        let reg = match axis {
+            Axis::X => Register::CS_X,
+            Axis::Y => Register::CS_Y,
+            Axis::Z => Register::CS_Z,
+        };
+        self.commbus.write_register(reg.write(), value as u8)
+    }
+
+    /// Read the per-axis constant-shift register (`CS_X/Y/Z`).
+    pub fn get_constant_shift(&mut self, axis: Axis) -> Result<i8, Error<E, PinError>> {
+        let reg = match axis {
+            Axis::X => Register::CS_X,
+            Axis::Y => Register::CS_Y,
+            Axis::Z => Register::CS_Z,
+        };
+        Ok(self.commbus.read_register(reg.read())? as i8)
+    }
+
+    /// Run a self-test: average several samples without and with the selected
+    /// stimulus applied, returning the per-axis delta to be checked against
+    /// the datasheet's expected range.
+    pub fn self_test<DELAY>(
+        &mut self,
+        mode: SelfTest,
+        delay: &mut DELAY,
+    ) -> Result<I16x3, Error<E, PinError>>
+    where
+        DELAY: hal::blocking::delay::DelayMs<u8>,
+    {
+        const SAMPLES: i32 = 5;
+
+        let baseline = self.average_samples(SAMPLES, delay)?;
+
+        self.set_self_test(mode)?;
+        // Allow the output to settle after enabling the stimulus.
+        delay.delay_ms(80_u8);
+        let stimulated = self.average_samples(SAMPLES, delay)?;
+
+        self.set_self_test(SelfTest::Off)?;
+        delay.delay_ms(80_u8);
+
+        Ok(I16x3::new(
+            (stimulated.0 - baseline.0) as i16,
+            (stimulated.1 - baseline.1) as i16,
+            (stimulated.2 - baseline.2) as i16,
+        ))
+    }
+
+    fn set_self_test(&mut self, mode: SelfTest) -> Result<(), Error<E, PinError>> {
+        let mut ctrl5 = self.commbus.read_register(Register::CTRL_REG5.read())?;
+        ctrl5 &= !ST_MASK;
+        ctrl5 |= (mode.bits() << ST_OFFSET) & ST_MASK;
+        self.commbus
+            .write_register(Register::CTRL_REG5.write(), ctrl5)
+    }
+
+    fn average_samples<DELAY>(
+        &mut self,
+        count: i32,
+        delay: &mut DELAY,
+    ) -> Result<(i32, i32, i32), Error<E, PinError>>
+    where
+        DELAY: hal::blocking::delay::DelayMs<u8>,
+    {
+        let (mut sx, mut sy, mut sz) = (0i32, 0i32, 0i32);
+        let mut bytes = [0u8; 6];
+        for _ in 0..count {
+            while !self.has_data()? {}
+            self.commbus
+                .read_bytes(Register::OUT_X_L.read(), &mut bytes)?;
+            sx += ((((bytes[1] as u16) << 8) | (bytes[0] as u16)) as i16) as i32;
+            sy += ((((bytes[3] as u16) << 8) | (bytes[2] as u16)) as i16) as i32;
+            sz += ((((bytes[5] as u16) << 8) | (bytes[4] as u16)) as i16) as i32;
+            delay.delay_ms(10_u8);
+        }
+        Ok((sx / count, sy / count, sz / count))
+    }
+
+    /// Read the stored samples into `buf`, at most `buf.len()` of them,
+    /// returning the count read.
+    pub fn read_fifo(&mut self, buf: &mut [I16x3]) -> Result<usize, Error<E, PinError>> {
+        let status = self.fifo_status()?;
+        // FSS tops out at 31, so a brim-full 32-sample FIFO is reported via
+        // the overrun flag; treat that as the full 32 samples.
+        let stored = if status.overrun() {
+            32
+        } else {
+            status.stored_samples() as usize
+        };
+        let count = core::cmp::min(stored, buf.len());
+
+        // The output-register auto-increment does not wrap back from OUT_Z_H
+        // into OUT_X_L, so drain one 6-byte triplet per sample.
+        let mut bytes = [0u8; 6];
+        for slot in buf.iter_mut().take(count) {
+            self.commbus
+                .read_bytes(Register::OUT_X_L.read(), &mut bytes)?;
+            let x = (((bytes[1] as u16) << 8) | (bytes[0] as u16)) as i16;
+            let y = (((bytes[3] as u16) << 8) | (bytes[2] as u16)) as i16;
+            let z = (((bytes[5] as u16) << 8) | (bytes[4] as u16)) as i16;
+            *slot = I16x3::new(x, y, z);
+        }
+
+        Ok(count)
+    }
 }
 
 impl<CB, E, PinError> Accelerometer for LIS3DSH<CB>
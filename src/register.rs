@@ -29,7 +29,7 @@ pub enum Register {
     CTRL_REG2 = 0x22,
     CTRL_REG3 = 0x23,
     CTRL_REG5 = 0x24,
-    CTRL_REG6 = 0x26,
+    CTRL_REG6 = 0x25,
 
     STATUS = 0x27, // 0xA7 read
 
@@ -39,6 +39,37 @@ pub enum Register {
     OUT_Y_H = 0x2B,
     OUT_Z_L = 0x2C,
     OUT_Z_H = 0x2D,
+
+    FIFO_CTRL = 0x2E,
+    FIFO_SRC = 0x2F,
+
+    // State machine 1 register bank
+    ST1_1 = 0x40,
+    TIM4_1 = 0x50,
+    TIM3_1 = 0x51,
+    TIM2_1 = 0x52,
+    TIM1_1 = 0x54,
+    THRS2_1 = 0x56,
+    THRS1_1 = 0x57,
+    MASK1_B = 0x59,
+    MASK1_A = 0x5A,
+    SETT1 = 0x5B,
+    PR1 = 0x5C,
+    OUTS1 = 0x5F,
+
+    // State machine 2 register bank
+    ST2_1 = 0x60,
+    TIM4_2 = 0x70,
+    TIM3_2 = 0x71,
+    TIM2_2 = 0x72,
+    TIM1_2 = 0x74,
+    THRS2_2 = 0x76,
+    THRS1_2 = 0x77,
+    MASK2_B = 0x79,
+    MASK2_A = 0x7A,
+    SETT2 = 0x7B,
+    PR2 = 0x7C,
+    OUTS2 = 0x7F,
 }
 
 impl Register {
@@ -120,6 +151,65 @@ impl DataRate {
         }
     }
 }
+/// FIFO operating mode (FIFO_CTRL bits 7:5).
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum FifoMode {
+    /// FIFO disabled, oldest sample overwritten.
+    Bypass = 0b000,
+    /// Stops collecting once full.
+    FIFO = 0b001,
+    /// Continuously overwrites oldest samples.
+    Stream = 0b010,
+    /// Stream until a trigger, then switch to FIFO.
+    StreamToFifo = 0b011,
+    /// Bypass until a trigger, then switch to Stream.
+    BypassToStream = 0b100,
+}
+
+impl FifoMode {
+    pub fn bits(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Contents of the FIFO_SRC register.
+pub struct FifoStatus(u8);
+
+impl FifoStatus {
+    const WTM_MASK: u8 = 0x1 << 7;
+    const OVRN_MASK: u8 = 0x1 << 6;
+    const EMPTY_MASK: u8 = 0x1 << 5;
+    const FSS_MASK: u8 = 0b0001_1111;
+
+    /// New from FIFO_SRC register
+    pub fn from(src: u8) -> FifoStatus {
+        FifoStatus(src)
+    }
+    /// Watermark level reached
+    pub fn watermark(&self) -> bool {
+        self.0 & Self::WTM_MASK != 0
+    }
+    /// FIFO overrun occurred
+    pub fn overrun(&self) -> bool {
+        self.0 & Self::OVRN_MASK != 0
+    }
+    /// FIFO is empty
+    pub fn empty(&self) -> bool {
+        self.0 & Self::EMPTY_MASK != 0
+    }
+    /// Number of unread samples currently stored
+    pub fn stored_samples(&self) -> u8 {
+        self.0 & Self::FSS_MASK
+    }
+}
+
+pub const FIFO_MODE_MASK: u8 = 0b1110_0000;
+pub const FIFO_MODE_OFFSET: u8 = 5;
+pub const FIFO_WTM_MASK: u8 = 0b0001_1111;
+pub const FIFO_EN: u8 = 0b0100_0000;
+
 pub struct DataStatus(u8);
 
 impl DataStatus {
@@ -195,3 +285,46 @@ pub const YDA: u8 = 0b0000_0010;
 pub const XDA: u8 = 0b0000_0001;
 
 pub const STRESET: u8 = 0b1;
+
+/// A single sensor axis.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+/// Self-test stimulus selection (CTRL_REG5 bits 2:1).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SelfTest {
+    /// Self-test disabled.
+    Off = 0b00,
+    /// Positive-sign stimulus.
+    Positive = 0b01,
+    /// Negative-sign stimulus.
+    Negative = 0b10,
+}
+
+impl SelfTest {
+    pub fn bits(self) -> u8 {
+        self as u8
+    }
+}
+
+pub const ST_MASK: u8 = 0b0000_0110;
+pub const ST_OFFSET: u8 = 1;
+
+/// CTRL_REG1 (SM1) / CTRL_REG2 (SM2) state-machine control bits.
+pub const SM_EN: u8 = 0b0000_0001;
+pub const SM_PIN_INT2: u8 = 0b0000_1000;
+
+/// CTRL_REG3 — interrupt routing and signal configuration.
+pub const DR_EN: u8 = 0b1000_0000;
+pub const IEA: u8 = 0b0100_0000;
+pub const IEL: u8 = 0b0010_0000;
+pub const INT2_EN: u8 = 0b0001_0000;
+pub const INT1_EN: u8 = 0b0000_1000;
+pub const VFILT: u8 = 0b0000_0100;
+
+/// CTRL_REG6 — route the FIFO watermark to the INT1 pin (P1_WTM, bit 2).
+pub const P1_WTM: u8 = 0b0000_0100;
@@ -0,0 +1,195 @@
+//! Program loader for the LIS3DSH's two embedded state-machine engines.
+//!
+//! Each state machine (SM1, SM2) runs up to 16 opcode steps over the
+//! acceleration stream, comparing against its own thresholds and timers to
+//! recognise gestures (free-fall, double-tap, wakeup, …) entirely on-chip.
+//! This module streams a [`Program`] into the correct register bank, enables
+//! the engine and routes its output to an interrupt pin.
+
+use core::fmt::Debug;
+
+use crate::commbus::CommBus;
+use crate::register::*;
+use crate::{Error, LIS3DSH};
+
+/// Which of the two embedded state machines to target.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StateMachine {
+    Sm1,
+    Sm2,
+}
+
+/// A single program step: a reset-condition nibble and a next-condition
+/// nibble (e.g. `GNTH1`, `LNTH2`, `TI1`, `NOP`).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub struct Step {
+    /// Condition that resets the machine to the start (high nibble).
+    pub reset: u8,
+    /// Condition that advances to the next step (low nibble).
+    pub next: u8,
+}
+
+impl Step {
+    pub const fn new(reset: u8, next: u8) -> Self {
+        Self { reset, next }
+    }
+
+    /// Encode the step as the single opcode byte stored in `STx_n`:
+    /// the reset condition is the high nibble, the next condition the low.
+    fn opcode(self) -> u8 {
+        ((self.reset & 0x0F) << 4) | (self.next & 0x0F)
+    }
+}
+
+/// An ordered program plus its threshold, timer and mask configuration.
+#[derive(Copy, Clone, Debug)]
+pub struct Program<'a> {
+    /// Ordered opcode steps (at most 16).
+    pub steps: &'a [Step],
+    pub thrs1: u8,
+    pub thrs2: u8,
+    pub tim1: u16,
+    pub tim2: u16,
+    pub tim3: u8,
+    pub tim4: u8,
+    pub mask_a: u8,
+    pub mask_b: u8,
+    pub settings: u8,
+}
+
+impl Default for Program<'_> {
+    fn default() -> Self {
+        Self {
+            steps: &[],
+            thrs1: 0,
+            thrs2: 0,
+            tim1: 0,
+            tim2: 0,
+            tim3: 0,
+            tim4: 0,
+            mask_a: 0,
+            mask_b: 0,
+            settings: 0,
+        }
+    }
+}
+
+/// Base register addresses for one state machine's bank.
+struct Bank {
+    st1: u8,
+    tim4: u8,
+    tim3: u8,
+    tim2: u8,
+    tim1: u8,
+    thrs2: u8,
+    thrs1: u8,
+    mask_b: u8,
+    mask_a: u8,
+    sett: u8,
+    pr: u8,
+    ctrl: Register,
+}
+
+impl StateMachine {
+    fn bank(self) -> Bank {
+        match self {
+            StateMachine::Sm1 => Bank {
+                st1: Register::ST1_1.addr(),
+                tim4: Register::TIM4_1.addr(),
+                tim3: Register::TIM3_1.addr(),
+                tim2: Register::TIM2_1.addr(),
+                tim1: Register::TIM1_1.addr(),
+                thrs2: Register::THRS2_1.addr(),
+                thrs1: Register::THRS1_1.addr(),
+                mask_b: Register::MASK1_B.addr(),
+                mask_a: Register::MASK1_A.addr(),
+                sett: Register::SETT1.addr(),
+                pr: Register::PR1.addr(),
+                ctrl: Register::CTRL_REG1,
+            },
+            StateMachine::Sm2 => Bank {
+                st1: Register::ST2_1.addr(),
+                tim4: Register::TIM4_2.addr(),
+                tim3: Register::TIM3_2.addr(),
+                tim2: Register::TIM2_2.addr(),
+                tim1: Register::TIM1_2.addr(),
+                thrs2: Register::THRS2_2.addr(),
+                thrs1: Register::THRS1_2.addr(),
+                mask_b: Register::MASK2_B.addr(),
+                mask_a: Register::MASK2_A.addr(),
+                sett: Register::SETT2.addr(),
+                pr: Register::PR2.addr(),
+                ctrl: Register::CTRL_REG2,
+            },
+        }
+    }
+}
+
+impl<CB, E, PinError> LIS3DSH<CB>
+where
+    CB: CommBus<CommError = crate::Error<E, PinError>>,
+    PinError: Debug,
+    E: Debug,
+{
+    /// Stream a program into the given engine, enable it and route its output
+    /// to an interrupt pin.
+    pub fn load_state_machine(
+        &mut self,
+        sm: StateMachine,
+        program: &Program,
+        route_int2: bool,
+    ) -> Result<(), Error<E, PinError>> {
+        if program.steps.len() > 16 {
+            return Err(Error::InvalidMode);
+        }
+
+        let bank = sm.bank();
+
+        self.commbus.write_register(bank.thrs1, program.thrs1)?;
+        self.commbus.write_register(bank.thrs2, program.thrs2)?;
+        self.commbus
+            .write_register(bank.tim1, (program.tim1 & 0xFF) as u8)?;
+        self.commbus
+            .write_register(bank.tim1 + 1, (program.tim1 >> 8) as u8)?;
+        self.commbus
+            .write_register(bank.tim2, (program.tim2 & 0xFF) as u8)?;
+        self.commbus
+            .write_register(bank.tim2 + 1, (program.tim2 >> 8) as u8)?;
+        self.commbus.write_register(bank.tim3, program.tim3)?;
+        self.commbus.write_register(bank.tim4, program.tim4)?;
+        self.commbus.write_register(bank.mask_a, program.mask_a)?;
+        self.commbus.write_register(bank.mask_b, program.mask_b)?;
+        self.commbus.write_register(bank.sett, program.settings)?;
+
+        // Reset the program counter and reset pointer to the first step so the
+        // engine starts executing from ST_1 rather than a stale position.
+        self.commbus.write_register(bank.pr, 0)?;
+
+        // Stream the opcode steps into STx_1..STx_16.
+        for (i, step) in program.steps.iter().enumerate() {
+            self.commbus
+                .write_register(bank.st1 + i as u8, step.opcode())?;
+        }
+
+        let mut ctrl = self.commbus.read_register(bank.ctrl.read())?;
+        ctrl |= SM_EN;
+        if route_int2 {
+            ctrl |= SM_PIN_INT2;
+        } else {
+            ctrl &= !SM_PIN_INT2;
+        }
+        self.commbus.write_register(bank.ctrl.write(), ctrl)
+    }
+
+    /// Read the state-machine output/flag register (`OUTS1`/`OUTS2`).
+    pub fn state_machine_output(
+        &mut self,
+        sm: StateMachine,
+    ) -> Result<u8, Error<E, PinError>> {
+        let reg = match sm {
+            StateMachine::Sm1 => Register::OUTS1,
+            StateMachine::Sm2 => Register::OUTS2,
+        };
+        self.commbus.read_register(reg.read())
+    }
+}